@@ -19,17 +19,191 @@ impl Paragraph {
     }
 }
 
+#[derive(Debug, Serialize)]
+struct HeadingAttrs {
+    level: u8,
+    id: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Heading {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    attrs: HeadingAttrs,
+    content: Vec<AdfNode>,
+}
+
+impl Heading {
+    fn new(level: u8, id: String) -> Self {
+        Self {
+            block_type: "heading",
+            attrs: HeadingAttrs { level, id },
+            content: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Table {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    content: Vec<AdfNode>,
+}
+
+impl Table {
+    fn new() -> Self {
+        Self {
+            block_type: "table",
+            content: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TableRow {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    content: Vec<AdfNode>,
+}
+
+impl TableRow {
+    fn new() -> Self {
+        Self {
+            block_type: "tableRow",
+            content: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TableCell {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    attrs: HashMap<String, String>,
+    content: Vec<AdfNode>,
+}
+
+impl TableCell {
+    /// Wraps `inline` in a `paragraph`, the way ADF table cells require, tagging the cell as
+    /// a `tableHeader` (first row) or `tableCell` and carrying the column's alignment, if any.
+    fn new(header: bool, align: markdown::mdast::AlignKind, inline: Vec<AdfNode>) -> Self {
+        let mut attrs = HashMap::new();
+        if let Some(align) = match align {
+            markdown::mdast::AlignKind::Left => Some("left"),
+            markdown::mdast::AlignKind::Right => Some("right"),
+            markdown::mdast::AlignKind::Center => Some("center"),
+            markdown::mdast::AlignKind::None => None,
+        } {
+            attrs.insert("align".to_string(), align.to_string());
+        }
+        let mut paragraph = Paragraph::new();
+        paragraph.content = inline;
+        Self {
+            block_type: if header { "tableHeader" } else { "tableCell" },
+            attrs,
+            content: vec![AdfNode::Paragraph(paragraph)],
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct BulletList {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    content: Vec<AdfNode>,
+}
+
+impl BulletList {
+    fn new(content: Vec<AdfNode>) -> Self {
+        Self {
+            block_type: "bulletList",
+            content,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct OrderedList {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    content: Vec<AdfNode>,
+}
+
+impl OrderedList {
+    fn new(content: Vec<AdfNode>) -> Self {
+        Self {
+            block_type: "orderedList",
+            content,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ListItem {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    content: Vec<AdfNode>,
+}
+
+impl ListItem {
+    fn new(content: Vec<AdfNode>) -> Self {
+        Self {
+            block_type: "listItem",
+            content,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TaskList {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    content: Vec<AdfNode>,
+}
+
+impl TaskList {
+    fn new(content: Vec<AdfNode>) -> Self {
+        Self {
+            block_type: "taskList",
+            content,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct TaskItemAttrs {
+    #[serde(rename = "localId")]
+    local_id: String,
+    state: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct TaskItem {
+    #[serde(rename = "type")]
+    block_type: &'static str,
+    attrs: TaskItemAttrs,
+    content: Vec<AdfNode>,
+}
+
 #[derive(Debug, Serialize)]
 struct CodeBlock {
     #[serde(rename = "type")]
     block_type: &'static str,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    attrs: HashMap<String, String>,
     content: Vec<AdfNode>,
 }
 
 impl CodeBlock {
-    fn new() -> Self {
+    fn new(language: Option<&str>) -> Self {
+        let mut attrs = HashMap::new();
+        if let Some(language) = language {
+            attrs.insert("language".to_string(), language.to_string());
+        }
         Self {
             block_type: "codeBlock",
+            attrs,
             content: vec![],
         }
     }
@@ -58,17 +232,43 @@ impl Text {
     }
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct Mark {
     #[serde(rename = "type")]
     block_type: &'static str,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
     attrs: HashMap<String, String>,
 }
 
+impl Mark {
+    fn new(block_type: &'static str) -> Self {
+        Self {
+            block_type,
+            attrs: HashMap::new(),
+        }
+    }
+
+    fn link(url: &str) -> Self {
+        Self {
+            block_type: "link",
+            attrs: HashMap::from([("href".to_string(), url.to_string())]),
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 enum AdfNode {
     Paragraph(Paragraph),
+    Heading(Heading),
+    Table(Table),
+    TableRow(TableRow),
+    TableCell(TableCell),
+    BulletList(BulletList),
+    OrderedList(OrderedList),
+    ListItem(ListItem),
+    TaskList(TaskList),
+    TaskItem(TaskItem),
     CodeBlock(CodeBlock),
     Text(Text),
     Mark(Mark),
@@ -90,13 +290,50 @@ impl<'a> ParagraphBuilder<'a> {
 
     fn link(self, text: &str, url: &str) -> Self {
         let mut text = Text::new(text);
-        text.add_mark(Mark {
-            block_type: "link",
-            attrs: HashMap::from([("href".to_string(), url.to_string())]),
-        });
+        text.add_mark(Mark::link(url));
         self.paragraph.content.push(AdfNode::Text(text));
         self
     }
+
+    /// Appends already-rendered inline nodes (see [`inline_nodes`]) to the paragraph.
+    fn inline(self, nodes: Vec<AdfNode>) -> Self {
+        self.paragraph.content.extend(nodes);
+        self
+    }
+}
+
+struct HeadingBuilder<'a> {
+    heading: &'a mut Heading,
+}
+
+impl<'a> HeadingBuilder<'a> {
+    fn new(heading: &'a mut Heading) -> Self {
+        Self { heading }
+    }
+
+    /// Appends already-rendered inline nodes (see [`inline_nodes`]) to the heading.
+    fn inline(self, nodes: Vec<AdfNode>) -> Self {
+        self.heading.content.extend(nodes);
+        self
+    }
+}
+
+struct TableBuilder<'a> {
+    table: &'a mut Table,
+}
+
+impl<'a> TableBuilder<'a> {
+    fn new(table: &'a mut Table) -> Self {
+        Self { table }
+    }
+
+    /// Appends a row built from already-rendered `tableCell`/`tableHeader` nodes.
+    fn row(self, cells: Vec<AdfNode>) -> Self {
+        let mut row = TableRow::new();
+        row.content = cells;
+        self.table.content.push(AdfNode::TableRow(row));
+        self
+    }
 }
 
 struct CodeBlockBuilder<'a> {
@@ -125,14 +362,46 @@ struct Document {
 #[derive(Debug)]
 struct DocumentBuilder {
     content: Vec<AdfNode>,
+    /// Tracks how many times each heading slug has been seen so far, mirroring rustdoc's
+    /// `IdMap`: the first occurrence keeps the plain slug, later ones get `-1`, `-2`, etc.
+    heading_ids: HashMap<String, usize>,
+    /// Monotonic counter used to hand out unique `localId`s to task items.
+    next_task_local_id: usize,
 }
 
 impl DocumentBuilder {
     fn new() -> Self {
-        Self { content: vec![] }
+        Self {
+            content: vec![],
+            heading_ids: HashMap::new(),
+            next_task_local_id: 0,
+        }
     }
 
-    fn paragraph(&mut self) -> ParagraphBuilder {
+    fn push(&mut self, node: AdfNode) {
+        self.content.push(node);
+    }
+
+    fn next_task_local_id(&mut self) -> String {
+        self.next_task_local_id += 1;
+        self.next_task_local_id.to_string()
+    }
+
+    /// Slugifies `text` (lowercase, spaces to hyphens, non-alphanumerics stripped) and
+    /// disambiguates it against slugs already produced by this builder.
+    fn unique_slug(&mut self, text: &str) -> String {
+        let base = slugify(text);
+        let mut slug = base.clone();
+        while self.heading_ids.contains_key(&slug) {
+            let seen = self.heading_ids.get_mut(&base).unwrap();
+            slug = format!("{base}-{seen}");
+            *seen += 1;
+        }
+        self.heading_ids.insert(slug.clone(), 1);
+        slug
+    }
+
+    fn paragraph(&mut self) -> ParagraphBuilder<'_> {
         self.content.push(AdfNode::Paragraph(Paragraph::new()));
         if let AdfNode::Paragraph(p) = self.content.last_mut().unwrap() {
             ParagraphBuilder::new(p)
@@ -141,8 +410,27 @@ impl DocumentBuilder {
         }
     }
 
-    fn code_block(&mut self) -> CodeBlockBuilder {
-        self.content.push(AdfNode::CodeBlock(CodeBlock::new()));
+    fn heading(&mut self, level: u8, id: String) -> HeadingBuilder<'_> {
+        self.content.push(AdfNode::Heading(Heading::new(level, id)));
+        if let AdfNode::Heading(h) = self.content.last_mut().unwrap() {
+            HeadingBuilder::new(h)
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn table(&mut self) -> TableBuilder<'_> {
+        self.content.push(AdfNode::Table(Table::new()));
+        if let AdfNode::Table(t) = self.content.last_mut().unwrap() {
+            TableBuilder::new(t)
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn code_block(&mut self, language: Option<&str>) -> CodeBlockBuilder<'_> {
+        self.content
+            .push(AdfNode::CodeBlock(CodeBlock::new(language)));
         if let AdfNode::CodeBlock(cb) = self.content.last_mut().unwrap() {
             CodeBlockBuilder::new(cb)
         } else {
@@ -159,39 +447,340 @@ impl DocumentBuilder {
     }
 }
 
+/// Slugifies heading text the way rustdoc's `IdMap` does: lowercase, spaces become hyphens,
+/// and anything else that isn't alphanumeric or a hyphen is stripped.
+fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '-')
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Flattens the text content of a slice of inline mdast nodes, ignoring marks, for use as
+/// heading-slug input.
+fn flatten_text(nodes: &[markdown::mdast::Node]) -> String {
+    let mut out = String::new();
+    for node in nodes {
+        match node {
+            markdown::mdast::Node::Text(t) => out.push_str(&t.value),
+            markdown::mdast::Node::InlineCode(c) => out.push_str(&c.value),
+            markdown::mdast::Node::Strong(s) => out.push_str(&flatten_text(&s.children)),
+            markdown::mdast::Node::Emphasis(e) => out.push_str(&flatten_text(&e.children)),
+            markdown::mdast::Node::Delete(d) => out.push_str(&flatten_text(&d.children)),
+            markdown::mdast::Node::Link(l) => out.push_str(&flatten_text(&l.children)),
+            markdown::mdast::Node::LinkReference(l) => out.push_str(&flatten_text(&l.children)),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Renders a slice of inline mdast nodes into ADF `text` nodes, threading `marks` down
+/// through container nodes (`Strong`, `Emphasis`, `Delete`) so that e.g. bold-inside-italic
+/// ends up as a single `Text` node carrying both marks.
+fn inline_nodes(
+    nodes: &[markdown::mdast::Node],
+    marks: &[Mark],
+    resolver: &LinkResolver,
+) -> anyhow::Result<Vec<AdfNode>> {
+    let mut out = vec![];
+    for node in nodes {
+        match node {
+            markdown::mdast::Node::Text(t) => {
+                let mut text = Text::new(&t.value);
+                text.marks = marks.to_vec();
+                out.push(AdfNode::Text(text));
+            }
+            markdown::mdast::Node::InlineCode(c) => {
+                let mut text = Text::new(&c.value);
+                text.marks = marks.to_vec();
+                text.add_mark(Mark::new("code"));
+                out.push(AdfNode::Text(text));
+            }
+            markdown::mdast::Node::Strong(s) => {
+                let mut marks = marks.to_vec();
+                marks.push(Mark::new("strong"));
+                out.extend(inline_nodes(&s.children, &marks, resolver)?);
+            }
+            markdown::mdast::Node::Emphasis(e) => {
+                let mut marks = marks.to_vec();
+                marks.push(Mark::new("em"));
+                out.extend(inline_nodes(&e.children, &marks, resolver)?);
+            }
+            markdown::mdast::Node::Delete(d) => {
+                let mut marks = marks.to_vec();
+                marks.push(Mark::new("strike"));
+                out.extend(inline_nodes(&d.children, &marks, resolver)?);
+            }
+            markdown::mdast::Node::Link(l) => {
+                // Use url as the link text if no text is provided
+                let text = l.children.first().map_or(l.url.as_str(), |v| {
+                    if let markdown::mdast::Node::Text(t) = v {
+                        t.value.as_str()
+                    } else {
+                        l.url.as_str()
+                    }
+                });
+                let mut text = Text::new(text);
+                text.marks = marks.to_vec();
+                text.add_mark(Mark::link(&resolver.resolve(&l.url)));
+                out.push(AdfNode::Text(text));
+            }
+            markdown::mdast::Node::LinkReference(l) => {
+                // Use the identifier as the link text if no text is provided
+                let text = l.children.first().map_or(l.identifier.as_str(), |v| {
+                    if let markdown::mdast::Node::Text(t) = v {
+                        t.value.as_str()
+                    } else {
+                        l.identifier.as_str()
+                    }
+                });
+                let mut text = Text::new(text);
+                text.marks = marks.to_vec();
+                text.add_mark(Mark::link(&resolver.resolve_reference(&l.identifier)));
+                out.push(AdfNode::Text(text));
+            }
+            node => anyhow::bail!(
+                "Only text-like inline nodes are supported, found {:?}",
+                node
+            ),
+        }
+    }
+    Ok(out)
+}
+
+/// Renders an mdast `List`, recursing into nested `List` children (a `listItem`/`taskItem`
+/// may itself contain another list) via [`list_item_content`] and [`task_item_content`].
+/// Items that carry a checkbox become ADF `taskItem`s in a `taskList` instead of `listItem`s
+/// in a `bulletList`/`orderedList`.
+/// ADF's schema doesn't allow mixing `listItem` and `taskItem` under one parent, so a list
+/// with only some items carrying a checkbox (e.g. a checklist followed by a plain note) is
+/// split into consecutive runs of checkbox/non-checkbox items, each rendered as its own
+/// `taskList` or `bulletList`/`orderedList` node, in source order.
+fn build_list(
+    list: &markdown::mdast::List,
+    document_builder: &mut DocumentBuilder,
+    resolver: &LinkResolver,
+) -> anyhow::Result<Vec<AdfNode>> {
+    let mut runs: Vec<(bool, Vec<&markdown::mdast::ListItem>)> = vec![];
+    for child in &list.children {
+        let markdown::mdast::Node::ListItem(li) = child else {
+            anyhow::bail!("Expected listItem inside list, found {:?}", child);
+        };
+        let is_task = li.checked.is_some();
+        match runs.last_mut() {
+            Some((run_is_task, items)) if *run_is_task == is_task => items.push(li),
+            _ => runs.push((is_task, vec![li])),
+        }
+    }
+
+    let mut nodes = vec![];
+    for (is_task, items) in runs {
+        if is_task {
+            let mut task_items = vec![];
+            for li in items {
+                let content = task_item_content(li, document_builder, resolver)?;
+                task_items.push(AdfNode::TaskItem(TaskItem {
+                    block_type: "taskItem",
+                    attrs: TaskItemAttrs {
+                        local_id: document_builder.next_task_local_id(),
+                        state: if li.checked.unwrap_or(false) {
+                            "DONE"
+                        } else {
+                            "TODO"
+                        },
+                    },
+                    content,
+                }));
+            }
+            nodes.push(AdfNode::TaskList(TaskList::new(task_items)));
+        } else {
+            let mut list_items = vec![];
+            for li in items {
+                list_items.push(AdfNode::ListItem(ListItem::new(list_item_content(
+                    li,
+                    document_builder,
+                    resolver,
+                )?)));
+            }
+            nodes.push(if list.ordered {
+                AdfNode::OrderedList(OrderedList::new(list_items))
+            } else {
+                AdfNode::BulletList(BulletList::new(list_items))
+            });
+        }
+    }
+    Ok(nodes)
+}
+
+/// Renders the children of an mdast `ListItem`: its text wrapped in a `paragraph`, plus a
+/// recursive call to [`build_list`] for any nested list.
+fn list_item_content(
+    li: &markdown::mdast::ListItem,
+    document_builder: &mut DocumentBuilder,
+    resolver: &LinkResolver,
+) -> anyhow::Result<Vec<AdfNode>> {
+    let mut content = vec![];
+    for child in &li.children {
+        match child {
+            markdown::mdast::Node::Paragraph(p) => {
+                let mut paragraph = Paragraph::new();
+                paragraph.content = inline_nodes(&p.children, &[], resolver)?;
+                content.push(AdfNode::Paragraph(paragraph));
+            }
+            markdown::mdast::Node::List(nested) => {
+                content.extend(build_list(nested, document_builder, resolver)?);
+            }
+            node => anyhow::bail!("Unsupported content in list item, found {:?}", node),
+        }
+    }
+    Ok(content)
+}
+
+/// Like [`list_item_content`], but for `taskItem`s: ADF wants the item's text as inline
+/// content directly rather than wrapped in a `paragraph`.
+fn task_item_content(
+    li: &markdown::mdast::ListItem,
+    document_builder: &mut DocumentBuilder,
+    resolver: &LinkResolver,
+) -> anyhow::Result<Vec<AdfNode>> {
+    let mut content = vec![];
+    for child in &li.children {
+        match child {
+            markdown::mdast::Node::Paragraph(p) => {
+                content.extend(inline_nodes(&p.children, &[], resolver)?);
+            }
+            markdown::mdast::Node::List(nested) => {
+                content.extend(build_list(nested, document_builder, resolver)?);
+            }
+            node => anyhow::bail!("Unsupported content in task item, found {:?}", node),
+        }
+    }
+    Ok(content)
+}
+
+/// Resolves link destinations the way rustdoc's `Markdown` struct resolves broken links:
+/// reference-style links (`[text][id]`) are looked up against the document's collected
+/// `Definition`s, and the result is then run through the caller-supplied replacement table,
+/// falling back to the original URL when no replacement applies.
+struct LinkResolver<'a> {
+    replacements: &'a [(String, String)],
+    definitions: HashMap<String, String>,
+}
+
+impl<'a> LinkResolver<'a> {
+    fn new(root: &[markdown::mdast::Node], replacements: &'a [(String, String)]) -> Self {
+        let mut definitions = HashMap::new();
+        for node in root {
+            if let markdown::mdast::Node::Definition(d) = node {
+                definitions.insert(d.identifier.clone(), d.url.clone());
+            }
+        }
+        Self {
+            replacements,
+            definitions,
+        }
+    }
+
+    /// Applies the replacement table to `url`, returning it unchanged if no entry matches.
+    fn resolve(&self, url: &str) -> String {
+        self.replacements
+            .iter()
+            .find(|(from, _)| from == url)
+            .map(|(_, to)| to.clone())
+            .unwrap_or_else(|| url.to_string())
+    }
+
+    /// Resolves a reference-style link's identifier to a URL via the document's definitions,
+    /// then applies the replacement table.
+    fn resolve_reference(&self, identifier: &str) -> String {
+        let url = self
+            .definitions
+            .get(identifier)
+            .map(String::as_str)
+            .unwrap_or_default();
+        self.resolve(url)
+    }
+}
+
+/// Converts Markdown to the Atlassian Document Format (ADF), as used by Confluence and Jira.
 pub fn from_markdown(md: &str) -> anyhow::Result<String> {
-    let md = markdown::to_mdast(md, &markdown::ParseOptions::default()).unwrap();
+    from_markdown_with_options(md, &[])
+}
+
+/// Like [`from_markdown`], but rewrites link destinations through `link_replacements` before
+/// they land in the `link` mark's `href` attr. Each entry is `(original_url, replacement_url)`,
+/// mirroring rustdoc's `&[(String, String)]` broken-link replacement table. Reference-style
+/// links (`[text][id]`) are resolved against the document's link definitions first.
+pub fn from_markdown_with_options(
+    md: &str,
+    link_replacements: &[(String, String)],
+) -> anyhow::Result<String> {
+    let md = markdown::to_mdast(md, &markdown::ParseOptions::gfm()).unwrap();
+    let children = md.children().unwrap();
+    let resolver = LinkResolver::new(children, link_replacements);
     let mut document_builder = DocumentBuilder::new();
-    for node in md.children().unwrap().iter() {
+    for node in children.iter() {
         match node {
             markdown::mdast::Node::Paragraph(p) => {
-                let mut paragraph = document_builder.paragraph();
-                for node in p.children.iter() {
-                    paragraph = match node {
-                        markdown::mdast::Node::Text(t) => paragraph.text(&t.value),
-                        markdown::mdast::Node::Link(l) => {
-                            // Use url as the link text if no text is provided
-                            let text = l.children.first().map_or(&l.url, |v| {
-                                if let markdown::mdast::Node::Text(t) = v {
-                                    &t.value
-                                } else {
-                                    &l.url
-                                }
-                            });
-                            paragraph.link(text, &l.url)
-                        }
-                        node => anyhow::bail!(
-                            "Only text nodes are supported for paragraph node, found {:?}",
-                            node
-                        ),
+                document_builder
+                    .paragraph()
+                    .inline(inline_nodes(&p.children, &[], &resolver)?);
+            }
+            markdown::mdast::Node::Heading(h) => {
+                let slug = document_builder.unique_slug(&flatten_text(&h.children));
+                document_builder.heading(h.depth, slug).inline(inline_nodes(
+                    &h.children,
+                    &[],
+                    &resolver,
+                )?);
+            }
+            markdown::mdast::Node::Table(t) => {
+                let mut table_builder = document_builder.table();
+                for (row_index, row) in t.children.iter().enumerate() {
+                    let markdown::mdast::Node::TableRow(row) = row else {
+                        anyhow::bail!("Expected tableRow inside table, found {:?}", row);
                     };
+                    let mut cells = vec![];
+                    for (col_index, cell) in row.children.iter().enumerate() {
+                        let markdown::mdast::Node::TableCell(cell) = cell else {
+                            anyhow::bail!("Expected tableCell inside tableRow, found {:?}", cell);
+                        };
+                        let align = t
+                            .align
+                            .get(col_index)
+                            .copied()
+                            .unwrap_or(markdown::mdast::AlignKind::None);
+                        cells.push(AdfNode::TableCell(TableCell::new(
+                            row_index == 0,
+                            align,
+                            inline_nodes(&cell.children, &[], &resolver)?,
+                        )));
+                    }
+                    table_builder = table_builder.row(cells);
+                }
+            }
+            markdown::mdast::Node::List(l) => {
+                for node in build_list(l, &mut document_builder, &resolver)? {
+                    document_builder.push(node);
                 }
             }
             markdown::mdast::Node::Code(c) => {
-                document_builder.code_block().text(&c.value);
+                document_builder
+                    .code_block(c.lang.as_deref())
+                    .text(&c.value);
+            }
+            markdown::mdast::Node::Definition(_) => {
+                // Already collected into the `LinkResolver`; definitions emit no ADF node.
             }
             node => anyhow::bail!(
-                "Only paragraph and code nodes are supported, found {:?}",
+                "Only paragraph, heading, table, list, code, and definition nodes are supported, found {:?}",
                 node
             ),
         }
@@ -237,6 +826,172 @@ mod tests {
         );
     }
 
+    #[test]
+    fn paragraph_with_marks() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "paragraph", "content": [
+                {"type": "text", "text": "bold", "marks": [{"type": "strong"}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "italic", "marks": [{"type": "em"}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "struck", "marks": [{"type": "strike"}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "code", "marks": [{"type": "code"}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "both", "marks": [{"type": "em"}, {"type": "strong"}]}
+            ]}
+        ]}"#;
+        let actual = from_markdown("**bold** *italic* ~~struck~~ `code` *__both__*").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn headings_with_slugs() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "heading", "attrs": {"level": 1, "id": "hello-world"}, "content": [
+                {"type": "text", "text": "Hello World"}
+            ]},
+            {"type": "heading", "attrs": {"level": 2, "id": "hello-world-1"}, "content": [
+                {"type": "text", "text": "Hello World"}
+            ]}
+        ]}"#;
+        let actual = from_markdown("# Hello World\n\n## Hello World").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn heading_slug_collapses_repeated_whitespace() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "heading", "attrs": {"level": 1, "id": "hello-world"}, "content": [
+                {"type": "text", "text": "Hello,  World!!"}
+            ]}
+        ]}"#;
+        let actual = from_markdown("# Hello,  World!!").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn heading_slug_includes_reference_style_link_text() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "heading", "attrs": {"level": 1, "id": "see-the-docs"}, "content": [
+                {"type": "text", "text": "See "},
+                {"type": "text", "text": "the docs", "marks": [{"type": "link", "attrs": {"href": "https://example.com/docs"}}]}
+            ]}
+        ]}"#;
+        let actual =
+            from_markdown("# See [the docs][id]\n\n[id]: https://example.com/docs").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn table_with_alignment() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "table", "content": [
+                {"type": "tableRow", "content": [
+                    {"type": "tableHeader", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Name"}]}]},
+                    {"type": "tableHeader", "attrs": {"align": "right"}, "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Age"}]}]}
+                ]},
+                {"type": "tableRow", "content": [
+                    {"type": "tableCell", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "Alice"}]}]},
+                    {"type": "tableCell", "attrs": {"align": "right"}, "content": [{"type": "paragraph", "content": [{"type": "text", "text": "30"}]}]}
+                ]}
+            ]}
+        ]}"#;
+        let actual = from_markdown("| Name | Age |\n| --- | ---: |\n| Alice | 30 |").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn nested_bullet_list() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "bulletList", "content": [
+                {"type": "listItem", "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "one"}]},
+                    {"type": "bulletList", "content": [
+                        {"type": "listItem", "content": [
+                            {"type": "paragraph", "content": [{"type": "text", "text": "nested"}]}
+                        ]}
+                    ]}
+                ]},
+                {"type": "listItem", "content": [
+                    {"type": "paragraph", "content": [{"type": "text", "text": "two"}]}
+                ]}
+            ]}
+        ]}"#;
+        let actual = from_markdown("- one\n  - nested\n- two").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn ordered_list() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "orderedList", "content": [
+                {"type": "listItem", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "first"}]}]},
+                {"type": "listItem", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "second"}]}]}
+            ]}
+        ]}"#;
+        let actual = from_markdown("1. first\n2. second").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn task_list() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "taskList", "content": [
+                {"type": "taskItem", "attrs": {"localId": "1", "state": "DONE"}, "content": [{"type": "text", "text": "done"}]},
+                {"type": "taskItem", "attrs": {"localId": "2", "state": "TODO"}, "content": [{"type": "text", "text": "todo"}]}
+            ]}
+        ]}"#;
+        let actual = from_markdown("- [x] done\n- [ ] todo").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn mixed_checkbox_and_plain_items_split_into_separate_lists() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "taskList", "content": [
+                {"type": "taskItem", "attrs": {"localId": "1", "state": "DONE"}, "content": [{"type": "text", "text": "one"}]},
+                {"type": "taskItem", "attrs": {"localId": "2", "state": "TODO"}, "content": [{"type": "text", "text": "two"}]}
+            ]},
+            {"type": "bulletList", "content": [
+                {"type": "listItem", "content": [{"type": "paragraph", "content": [{"type": "text", "text": "three (no checkbox)"}]}]}
+            ]},
+            {"type": "taskList", "content": [
+                {"type": "taskItem", "attrs": {"localId": "3", "state": "DONE"}, "content": [{"type": "text", "text": "four"}]}
+            ]}
+        ]}"#;
+        let actual =
+            from_markdown("- [x] one\n- [ ] two\n- three (no checkbox)\n- [x] four").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
     #[test]
     fn code_block() {
         let expected = r#"{"version": 1, "type": "doc", "content": [{"type": "codeBlock", "content": [{"type": "text", "text": "a = 42"}]}]}"#;
@@ -246,4 +1001,51 @@ mod tests {
             actual.parse::<serde_json::Value>().unwrap()
         );
     }
+
+    #[test]
+    fn code_block_with_language() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [{"type": "codeBlock", "attrs": {"language": "rust"}, "content": [{"type": "text", "text": "let a = 42;"}]}]}"#;
+        let actual = from_markdown("```rust\nlet a = 42;\n```").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn reference_style_link_resolves_against_definition() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "paragraph", "content": [
+                {"type": "text", "text": "see "},
+                {"type": "text", "text": "the docs", "marks": [{"type": "link", "attrs": {"href": "https://example.com/docs"}}]}
+            ]}
+        ]}"#;
+        let actual = from_markdown("see [the docs][id]\n\n[id]: https://example.com/docs").unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
+
+    #[test]
+    fn link_replacements_rewrite_destinations() {
+        let expected = r#"{"version": 1, "type": "doc", "content": [
+            {"type": "paragraph", "content": [
+                {"type": "text", "text": "relative", "marks": [{"type": "link", "attrs": {"href": "https://confluence.example.com/page"}}]},
+                {"type": "text", "text": " "},
+                {"type": "text", "text": "unmapped", "marks": [{"type": "link", "attrs": {"href": "/other"}}]}
+            ]}
+        ]}"#;
+        let replacements = vec![(
+            "/page".to_string(),
+            "https://confluence.example.com/page".to_string(),
+        )];
+        let actual =
+            from_markdown_with_options("[relative](/page) [unmapped](/other)", &replacements)
+                .unwrap();
+        assert_eq!(
+            expected.parse::<serde_json::Value>().unwrap(),
+            actual.parse::<serde_json::Value>().unwrap()
+        );
+    }
 }